@@ -51,6 +51,7 @@ import { FitAddon } from "@xterm/addon-fit";
 import { Terminal as XtermTerminal, type ITheme } from "@xterm/xterm";
 import "@xterm/xterm/css/xterm.css";
 import {
+  CommandError,
   createDirectory,
   createFile,
   deletePath,
@@ -371,21 +372,8 @@ const MOVE_PATH_ERROR_MESSAGES: Record<MovePathErrorCode, string> = {
 };
 
 function parseMovePathErrorCode(error: unknown): MovePathErrorCode | null {
-  const message = String(error ?? "");
-  if (message.startsWith("MOVE_SOURCE_IS_ROOT")) {
-    return "MOVE_SOURCE_IS_ROOT";
-  }
-  if (message.startsWith("MOVE_TARGET_NOT_DIRECTORY")) {
-    return "MOVE_TARGET_NOT_DIRECTORY";
-  }
-  if (message.startsWith("MOVE_TARGET_EXISTS")) {
-    return "MOVE_TARGET_EXISTS";
-  }
-  if (message.startsWith("MOVE_TARGET_INSIDE_SOURCE")) {
-    return "MOVE_TARGET_INSIDE_SOURCE";
-  }
-  if (message.startsWith("MOVE_IO_ERROR")) {
-    return "MOVE_IO_ERROR";
+  if (error instanceof CommandError && error.code in MOVE_PATH_ERROR_MESSAGES) {
+    return error.code as MovePathErrorCode;
   }
 
   return null;
@@ -1,9 +1,11 @@
-import { invoke } from "@tauri-apps/api/core";
+import { invoke as invokeCommand } from "@tauri-apps/api/core";
 import type {
   Ack,
   AiProviderSuggestion,
   AiRunRequest,
   AiRunResult,
+  CommandErrorCode,
+  CommandErrorPayload,
   FileContent,
   FileNode,
   GitBranchSnapshot,
@@ -15,12 +17,46 @@ import type {
   LspSessionInfo,
   PathResult,
   SaveResult,
-  SearchHit,
+  SearchJob,
+  SearchOptions,
   TerminalSession,
   TerminalSessionSnapshot,
   WorkspaceInfo,
 } from "./types";
 
+export class CommandError extends Error {
+  readonly code: CommandErrorCode;
+  readonly details: unknown;
+
+  constructor(payload: CommandErrorPayload) {
+    super(payload.message);
+    this.name = "CommandError";
+    this.code = payload.code;
+    this.details = payload.details ?? null;
+  }
+
+  toString(): string {
+    return this.message;
+  }
+}
+
+function isCommandErrorPayload(error: unknown): error is CommandErrorPayload {
+  return (
+    typeof error === "object" &&
+    error !== null &&
+    typeof (error as CommandErrorPayload).code === "string" &&
+    typeof (error as CommandErrorPayload).message === "string"
+  );
+}
+
+async function invoke<T>(command: string, args?: Record<string, unknown>): Promise<T> {
+  try {
+    return await invokeCommand<T>(command, args);
+  } catch (error) {
+    throw isCommandErrorPayload(error) ? new CommandError(error) : error;
+  }
+}
+
 export async function setWorkspace(path: string): Promise<WorkspaceInfo> {
   return invoke<WorkspaceInfo>("set_workspace", { path });
 }
@@ -36,8 +72,8 @@ export async function listDirectory(path?: string, includeHidden = false): Promi
   });
 }
 
-export async function readFile(path: string): Promise<FileContent> {
-  return invoke<FileContent>("read_file", { path });
+export async function readFile(path: string, readOnly = false): Promise<FileContent> {
+  return invoke<FileContent>("read_file", { path, readOnly });
 }
 
 export async function writeFile(path: string, content: string): Promise<SaveResult> {
@@ -71,14 +107,20 @@ export async function searchWorkspace(
   query: string,
   maxResults = 200,
   includeHidden = false,
-): Promise<SearchHit[]> {
-  return invoke<SearchHit[]>("search_workspace", {
+  options?: SearchOptions,
+): Promise<SearchJob> {
+  return invoke<SearchJob>("search_workspace", {
     query,
     maxResults,
     includeHidden,
+    options: options ?? null,
   });
 }
 
+export async function searchCancel(jobId: string): Promise<Ack> {
+  return invoke<Ack>("search_cancel", { jobId });
+}
+
 export async function terminalCreate(shell = "powershell.exe"): Promise<TerminalSessionSnapshot> {
   return invoke<TerminalSessionSnapshot>("terminal_create", { shell });
 }
@@ -162,8 +204,14 @@ export async function lspStart(
   server: string,
   args: string[],
   rootPath: string,
+  languageId?: string,
 ): Promise<LspSessionInfo> {
-  return invoke<LspSessionInfo>("lsp_start", { server, args, rootPath });
+  return invoke<LspSessionInfo>("lsp_start", {
+    server,
+    args,
+    rootPath,
+    languageId: languageId ?? null,
+  });
 }
 
 export async function lspSend(sessionId: string, payload: string): Promise<Ack> {
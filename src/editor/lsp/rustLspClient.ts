@@ -238,9 +238,11 @@ export function createRustLspClient(options: CreateRustLspClientOptions): RustLs
     }
 
     try {
-      session = await lspStart("rust-analyzer", [], nextWorkspaceRoot);
+      session = await lspStart("rust-analyzer", [], nextWorkspaceRoot, "rust");
       workspaceRoot = nextWorkspaceRoot;
-      await sendInitialize();
+      if (!session.attached) {
+        await sendInitialize();
+      }
       options.onOutput({
         channel: "lsp",
         level: "info",
@@ -24,6 +24,7 @@ export interface FileNode {
 export interface FileContent {
   path: string;
   content: string;
+  readOnly: boolean;
 }
 
 export interface SaveResult {
@@ -42,11 +43,76 @@ export type MovePathErrorCode =
   | "MOVE_TARGET_INSIDE_SOURCE"
   | "MOVE_IO_ERROR";
 
+export type CommandErrorCode =
+  | MovePathErrorCode
+  | "WORKSPACE_NOT_SET"
+  | "PATH_OUTSIDE_WORKSPACE"
+  | "GIT_AUTH_REQUIRED"
+  | "GIT_NOT_REPOSITORY"
+  | "AI_COMMAND_NOT_ALLOWED"
+  | "TIMEOUT"
+  | "CANCELLED"
+  | "ALREADY_EXISTS"
+  | "NOT_FOUND"
+  | "PERMISSION_DENIED"
+  | "INVALID_ARGUMENT"
+  | "FAILED_PRECONDITION"
+  | "TASK_FAILED"
+  | "INTERNAL";
+
+export interface CommandErrorPayload {
+  code: CommandErrorCode;
+  message: string;
+  details?: unknown;
+}
+
+export interface SearchMatchRange {
+  startColumn: number;
+  endColumn: number;
+}
+
 export interface SearchHit {
   path: string;
   line: number;
   column: number;
+  endLine: number;
+  endColumn: number;
+  matchLength: number;
+  matches: SearchMatchRange[];
   preview: string;
+  contextBefore: string[];
+  contextAfter: string[];
+}
+
+export interface SearchOptions {
+  caseSensitive?: boolean;
+  wholeWord?: boolean;
+  useRegex?: boolean;
+  includeGlobs?: string[];
+  excludeGlobs?: string[];
+  scopePath?: string;
+  contextBefore?: number;
+  contextAfter?: number;
+  maxFileSizeKb?: number;
+  binarySniffBytes?: number;
+  maxLineLength?: number;
+  includeMinified?: boolean;
+}
+
+export interface SearchJob {
+  jobId: string | null;
+}
+
+export interface SearchHitEvent {
+  jobId: string;
+  hit: SearchHit;
+}
+
+export interface SearchDoneEvent {
+  jobId: string;
+  hitCount: number;
+  cancelled: boolean;
+  error: string | null;
 }
 
 export interface TerminalSession {
@@ -65,6 +131,8 @@ export interface TerminalCommandResult {
   error: string;
   exitCode: number;
   cwd: string;
+  timedOut: boolean;
+  truncated: boolean;
 }
 
 export interface TerminalSessionSnapshot {
@@ -153,14 +221,80 @@ export interface AiProviderSuggestion {
   id: string;
   command: string;
   argsTemplate: string[];
+  promptMode: AiPromptMode;
   description: string;
 }
 
+export type AiPromptMode = "argv" | "stdin";
+
+export type AiHttpProviderKind = "openai" | "anthropic" | "ollama" | "llamacpp";
+
+export interface AiHttpProviderConfig {
+  kind: AiHttpProviderKind;
+  model: string;
+  baseUrl?: string;
+  apiKeyEnv?: string;
+  secret?: string;
+  system?: string;
+  maxTokens?: number;
+  temperature?: number;
+}
+
 export interface AiRunRequest {
   command: string;
   args: string[];
   prompt: string;
+  promptMode?: AiPromptMode;
   cwd?: string;
+  provider?: AiHttpProviderConfig;
+  providerId?: string;
+  model?: string;
+  runId?: string;
+  timeoutMs?: number;
+  maxOutputBytes?: number;
+}
+
+export type AiProviderKind = "cli" | AiHttpProviderKind;
+
+export interface AiLocalModel {
+  kind: "ollama" | "llamacpp";
+  name: string;
+  baseUrl: string;
+  size?: number | null;
+  family?: string | null;
+  parameterSize?: string | null;
+}
+
+export interface AiLocalModelList {
+  models: AiLocalModel[];
+  ollamaAvailable: boolean;
+  llamacppAvailable: boolean;
+}
+
+export interface AiInlineCompletion {
+  completion: string;
+  cached: boolean;
+  cancelled: boolean;
+}
+
+export interface AiProviderConfig {
+  id: string;
+  name?: string;
+  kind: AiProviderKind;
+  command?: string;
+  argsTemplate?: string[];
+  promptMode?: AiPromptMode;
+  env?: Record<string, string>;
+  baseUrl?: string;
+  defaultModel?: string;
+  apiKeyEnv?: string;
+  secret?: string;
+}
+
+export interface AiOutputEvent {
+  runId: string;
+  chunk: string;
+  done: boolean;
 }
 
 export interface AiRunResult {
@@ -170,6 +304,160 @@ export interface AiRunResult {
   stderr: string;
   exitCode: number;
   success: boolean;
+  timedOut: boolean;
+  truncated: boolean;
+  runId?: string;
+  codeBlocks: AiCodeBlock[];
+  redactions: AiRedaction[];
+}
+
+export interface AiCommandAllowlist {
+  global: string[];
+  workspaces: Record<string, string[]>;
+}
+
+export interface AiCommandApprovalRequestEvent {
+  requestId: string;
+  command: string;
+  workspace?: string | null;
+}
+
+export interface AiRedaction {
+  kind: string;
+  count: number;
+}
+
+export interface AiRedactedText {
+  text: string;
+  redactions: AiRedaction[];
+}
+
+export interface AiRedactionEvent {
+  runId: string;
+  redactions: AiRedaction[];
+}
+
+export interface AiDiagnostic {
+  line: number;
+  column: number;
+  endLine?: number;
+  endColumn?: number;
+  severity?: string;
+  message: string;
+  code?: string;
+  source?: string;
+  related?: { path?: string; line?: number; message: string }[];
+}
+
+export interface AiDiagnosticExplanation {
+  path: string;
+  line: number;
+  column: number;
+  endLine?: number | null;
+  endColumn?: number | null;
+  result: AiRunResult;
+}
+
+export interface AiCodeBlock {
+  language?: string | null;
+  path?: string | null;
+  content: string;
+  startLine: number;
+  endLine: number;
+  diff: boolean;
+}
+
+export interface AiRunInfo {
+  runId: string;
+  kind: "run" | "session" | "commitMessage" | "agent";
+  command: string;
+  sessionId?: string;
+  startedMs: number;
+}
+
+export interface AiAgentRequest extends AiRunRequest {
+  maxSteps?: number;
+}
+
+export type AiAgentToolName = "read_file" | "search" | "run_command" | "edit_file";
+
+export interface AiAgentStep {
+  tool: string;
+  arguments: unknown;
+  approved: boolean;
+  success: boolean;
+  output: string;
+}
+
+export interface AiAgentResult {
+  runId: string;
+  answer?: string | null;
+  completed: boolean;
+  steps: AiAgentStep[];
+  checkpointId?: string | null;
+}
+
+export interface CheckpointInfo {
+  id: string;
+  label: string;
+  runId?: string | null;
+  createdMs: number;
+  gitHead?: string | null;
+  gitBranch?: string | null;
+  files: string[];
+}
+
+export interface CheckpointRestoreResult {
+  id: string;
+  restored: string[];
+  removed: string[];
+  headChanged: boolean;
+}
+
+export interface AiAgentStepEvent {
+  runId: string;
+  step: AiAgentStep;
+}
+
+export interface AiAgentApprovalRequestEvent {
+  requestId: string;
+  runId: string;
+  tool: AiAgentToolName;
+  arguments: unknown;
+}
+
+export type AiSessionExportFormat = "markdown" | "json";
+
+export interface AiSessionExport {
+  path: string;
+  format: AiSessionExportFormat;
+  bytes: number;
+}
+
+export interface AiRunEvent {
+  event: "started" | "finished";
+  run: AiRunInfo;
+}
+
+export interface SessionEditor {
+  path: string;
+  line: number;
+  column: number;
+  scrollTop?: number | null;
+}
+
+export interface SessionTerminal {
+  title: string;
+  shell: string;
+  cwd: string;
+}
+
+export interface AppSessionSnapshot {
+  workspaceRoot?: string | null;
+  editors: SessionEditor[];
+  activePath?: string | null;
+  terminals: SessionTerminal[];
+  savedMs: number;
 }
 
 export interface EditorTab {
@@ -254,6 +542,11 @@ export interface LspSessionInfo {
   server: string;
   rootPath: string;
   status: string;
+  registryId: string | null;
+  initialized: boolean;
+  restartCount: number;
+  refCount: number;
+  attached: boolean;
 }
 
 export interface LspMessageEvent {